@@ -1,15 +1,25 @@
 use std::fs;
 use std::path::PathBuf;
+use std::str::FromStr;
 use std::sync::Mutex;
 use tauri::{
     Emitter, LogicalSize, Manager, Size, WebviewWindow,
-    menu::{Menu, MenuItem},
+    menu::{CheckMenuItem, Menu, MenuItem},
     tray::{MouseButton, MouseButtonState, TrayIconBuilder, TrayIconEvent},
 };
 use tauri_plugin_global_shortcut::{Code, GlobalShortcutExt, Modifiers, Shortcut};
 use serde::{Deserialize, Serialize};
 
-// Settings struct
+mod window_state;
+mod snap;
+mod secret_store;
+#[cfg(feature = "overlay")]
+mod overlay;
+
+// Settings struct. This is the shape the frontend sees over IPC (`load_settings`
+// / `save_settings`), so `api_key` is a normal field here -- it must round-trip
+// like any other setting. What never round-trips through it is the *disk* file;
+// see `PersistedSettings` below for that half.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Settings {
     pub pal_name: String,
@@ -18,6 +28,17 @@ pub struct Settings {
     pub notifications_enabled: bool,
     pub default_mode: String,
     pub api_key: String,
+    pub visible_on_all_workspaces: bool,
+    pub toggle_shortcut: String,
+    pub snap_anchor: Option<String>,
+}
+
+fn default_visible_on_all_workspaces() -> bool {
+    true
+}
+
+fn default_toggle_shortcut() -> String {
+    "Ctrl+Shift+P".to_string()
 }
 
 impl Default for Settings {
@@ -29,17 +50,131 @@ impl Default for Settings {
             notifications_enabled: true,
             default_mode: "full".to_string(),
             api_key: "".to_string(),
+            visible_on_all_workspaces: true,
+            toggle_shortcut: default_toggle_shortcut(),
+            snap_anchor: None,
+        }
+    }
+}
+
+// The on-disk shape of settings.json. Deliberately has no `api_key` field at
+// all, so the secret can never be serialized to disk no matter how
+// `Settings` itself is used elsewhere (e.g. returned from the `load_settings`
+// command). The key lives in the OS secure store instead; see `secret_store`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct PersistedSettings {
+    pal_name: String,
+    owner_name: String,
+    sounds_enabled: bool,
+    notifications_enabled: bool,
+    default_mode: String,
+    #[serde(default = "default_visible_on_all_workspaces")]
+    visible_on_all_workspaces: bool,
+    #[serde(default = "default_toggle_shortcut")]
+    toggle_shortcut: String,
+    #[serde(default)]
+    snap_anchor: Option<String>,
+}
+
+impl Default for PersistedSettings {
+    fn default() -> Self {
+        Settings::default().into()
+    }
+}
+
+impl From<Settings> for PersistedSettings {
+    fn from(settings: Settings) -> Self {
+        Self {
+            pal_name: settings.pal_name,
+            owner_name: settings.owner_name,
+            sounds_enabled: settings.sounds_enabled,
+            notifications_enabled: settings.notifications_enabled,
+            default_mode: settings.default_mode,
+            visible_on_all_workspaces: settings.visible_on_all_workspaces,
+            toggle_shortcut: settings.toggle_shortcut,
+            snap_anchor: settings.snap_anchor,
+        }
+    }
+}
+
+impl From<PersistedSettings> for Settings {
+    fn from(persisted: PersistedSettings) -> Self {
+        Self {
+            pal_name: persisted.pal_name,
+            owner_name: persisted.owner_name,
+            sounds_enabled: persisted.sounds_enabled,
+            notifications_enabled: persisted.notifications_enabled,
+            default_mode: persisted.default_mode,
+            api_key: String::new(),
+            visible_on_all_workspaces: persisted.visible_on_all_workspaces,
+            toggle_shortcut: persisted.toggle_shortcut,
+            snap_anchor: persisted.snap_anchor,
         }
     }
 }
 
+// Parses an accelerator string like "Ctrl+Shift+P" into a registerable shortcut.
+fn parse_accelerator(accelerator: &str) -> Result<Shortcut, String> {
+    let mut modifiers = Modifiers::empty();
+    let mut code = None;
+
+    for part in accelerator.split('+') {
+        let part = part.trim();
+        match part.to_lowercase().as_str() {
+            "" => return Err(format!("Malformed accelerator: {}", accelerator)),
+            "ctrl" | "control" => modifiers |= Modifiers::CONTROL,
+            "shift" => modifiers |= Modifiers::SHIFT,
+            "alt" | "option" => modifiers |= Modifiers::ALT,
+            "cmd" | "command" | "super" | "meta" => modifiers |= Modifiers::SUPER,
+            key => {
+                code = Some(parse_key_code(key)?);
+            }
+        }
+    }
+
+    let code = code.ok_or_else(|| format!("Accelerator has no key: {}", accelerator))?;
+    Ok(Shortcut::new(Some(modifiers), code))
+}
+
+fn parse_key_code(key: &str) -> Result<Code, String> {
+    if key.len() == 1 {
+        let ch = key.chars().next().unwrap().to_ascii_uppercase();
+        if ch.is_ascii_alphabetic() {
+            return Code::from_str(&format!("Key{}", ch)).map_err(|_| format!("Unknown key: {}", key));
+        }
+        if ch.is_ascii_digit() {
+            return Code::from_str(&format!("Digit{}", ch)).map_err(|_| format!("Unknown key: {}", key));
+        }
+    }
+
+    match key.to_lowercase().as_str() {
+        "space" => Ok(Code::Space),
+        "enter" | "return" => Ok(Code::Enter),
+        "tab" => Ok(Code::Tab),
+        "escape" | "esc" => Ok(Code::Escape),
+        other if other.starts_with('f') && other[1..].parse::<u8>().is_ok() => {
+            Code::from_str(&format!("F{}", &other[1..])).map_err(|_| format!("Unknown key: {}", key))
+        }
+        _ => Err(format!("Unknown key: {}", key)),
+    }
+}
+
 // Global state for hide timer
 struct HideState {
     hide_until: Option<std::time::Instant>,
 }
 
+// Tracks which window mode is currently active, so we know which key to save
+// geometry under on close without threading the mode through every call site.
+struct CurrentMode(Mutex<String>);
+
+// Tracks the timestamp of the most recent `Moved` event so we can detect a
+// drag release: if nothing moves the window again within the debounce
+// window, the last move was the end of a drag.
+struct DragState(Mutex<Option<std::time::Instant>>);
+
 // Get config directory
-fn get_config_dir() -> PathBuf {
+pub(crate) fn get_config_dir() -> PathBuf {
     dirs::config_dir()
         .unwrap_or_else(|| PathBuf::from("."))
         .join("Pal")
@@ -50,26 +185,66 @@ fn get_config_path() -> PathBuf {
     get_config_dir().join("settings.json")
 }
 
-// Load settings from file
+// Load settings from file, without touching the secure store. Use this for
+// internal reads that only care about the non-secret fields (window mode,
+// snap anchor, shortcut, ...) so they aren't slowed down by a keychain round
+// trip on every tray click or mode switch. `api_key` always comes back empty;
+// it never lives in `PersistedSettings` in the first place.
 fn load_settings_from_file() -> Settings {
     let path = get_config_path();
     if path.exists() {
         if let Ok(content) = fs::read_to_string(&path) {
-            if let Ok(settings) = serde_json::from_str(&content) {
-                return settings;
+            if let Ok(persisted) = serde_json::from_str::<PersistedSettings>(&content) {
+                return persisted.into();
             }
         }
     }
     Settings::default()
 }
 
-// Save settings to file
+// Load settings and hydrate `api_key` from the secure store. Use this for
+// anything that surfaces settings to the frontend.
+fn load_settings_with_api_key() -> Settings {
+    let mut settings = load_settings_from_file();
+
+    // One-time migration: older settings.json files may still have a
+    // plaintext api_key -- from before `PersistedSettings` existed, it was
+    // just `Settings` serialized whole. `PersistedSettings` has no field to
+    // deserialize that into, so read the raw JSON instead to find it. Move
+    // it into the secure store and rewrite the file (now naturally without
+    // it). If the secure store write fails, leave the plaintext key in
+    // `settings.json` rather than losing it.
+    let legacy_plaintext_key = fs::read_to_string(get_config_path())
+        .ok()
+        .and_then(|content| serde_json::from_str::<serde_json::Value>(&content).ok())
+        .and_then(|value| value.get("api_key")?.as_str().map(str::to_string))
+        .filter(|key| !key.is_empty());
+
+    if let Some(plaintext_key) = legacy_plaintext_key {
+        match secret_store::set_api_key(&plaintext_key) {
+            Ok(()) => {
+                let _ = save_settings_to_file(&settings);
+                settings.api_key = secret_store::get_api_key().unwrap_or_default();
+            }
+            Err(_) => settings.api_key = plaintext_key,
+        }
+        return settings;
+    }
+
+    settings.api_key = secret_store::get_api_key().unwrap_or_default();
+    settings
+}
+
+// Save settings to file. `api_key` is never part of `PersistedSettings`, so
+// converting through it keeps the secret out of settings.json regardless of
+// what `settings.api_key` holds.
 fn save_settings_to_file(settings: &Settings) -> Result<(), String> {
     let dir = get_config_dir();
     fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
 
+    let persisted: PersistedSettings = settings.clone().into();
     let path = get_config_path();
-    let content = serde_json::to_string_pretty(settings).map_err(|e| e.to_string())?;
+    let content = serde_json::to_string_pretty(&persisted).map_err(|e| e.to_string())?;
     fs::write(&path, content).map_err(|e| e.to_string())?;
     Ok(())
 }
@@ -81,56 +256,104 @@ fn greet(name: &str) -> String {
 
 #[tauri::command]
 async fn set_window_mode(window: WebviewWindow, mode: &str) -> Result<(), String> {
-    apply_window_mode(&window, mode)
+    if let Some(state) = window.try_state::<CurrentMode>() {
+        let old_mode = state.0.lock().unwrap().clone();
+        let _ = window_state::capture_window_state(&window, &old_mode);
+    }
+
+    apply_window_mode(&window, mode)?;
+    if let Some(state) = window.try_state::<CurrentMode>() {
+        *state.0.lock().unwrap() = mode.to_string();
+    }
+    Ok(())
 }
 
 fn apply_window_mode(window: &WebviewWindow, mode: &str) -> Result<(), String> {
-    match mode {
-        "full" => {
-            window.set_size(Size::Logical(LogicalSize::new(400.0, 500.0)))
-                .map_err(|e| e.to_string())?;
-            window.set_always_on_top(false)
-                .map_err(|e| e.to_string())?;
-            window.set_decorations(false)
-                .map_err(|e| e.to_string())?;
-            window.set_resizable(true)
-                .map_err(|e| e.to_string())?;
-        },
-        "widget" => {
-            window.set_size(Size::Logical(LogicalSize::new(220.0, 180.0)))
-                .map_err(|e| e.to_string())?;
-            window.set_always_on_top(true)
-                .map_err(|e| e.to_string())?;
-            window.set_decorations(false)
-                .map_err(|e| e.to_string())?;
-            window.set_resizable(false)
-                .map_err(|e| e.to_string())?;
-        },
-        "floating" => {
-            window.set_size(Size::Logical(LogicalSize::new(150.0, 100.0)))
-                .map_err(|e| e.to_string())?;
-            window.set_always_on_top(true)
-                .map_err(|e| e.to_string())?;
-            window.set_decorations(false)
-                .map_err(|e| e.to_string())?;
-            window.set_resizable(false)
-                .map_err(|e| e.to_string())?;
-        },
+    let (default_width, default_height, always_on_top, resizable, all_workspaces) = match mode {
+        "full" => (400.0, 500.0, false, true, false),
+        "widget" => (220.0, 180.0, true, false, true),
+        "floating" => (150.0, 100.0, true, false, true),
         _ => return Err(format!("Unknown mode: {}", mode)),
+    };
+
+    window.set_always_on_top(always_on_top)
+        .map_err(|e| e.to_string())?;
+    window.set_decorations(false)
+        .map_err(|e| e.to_string())?;
+    window.set_resizable(resizable)
+        .map_err(|e| e.to_string())?;
+
+    // Users can opt out of the sticky-across-workspaces behavior entirely.
+    let settings = load_settings_from_file();
+    window
+        .set_visible_on_all_workspaces(all_workspaces && settings.visible_on_all_workspaces)
+        .map_err(|e| e.to_string())?;
+
+    // Only fall back to the hardcoded size for this mode if we have no
+    // remembered geometry to restore.
+    if !window_state::restore_window_state(window, mode)? {
+        window.set_size(Size::Logical(LogicalSize::new(default_width, default_height)))
+            .map_err(|e| e.to_string())?;
+
+        // No remembered geometry either; fall back to the last anchor the
+        // user snapped this window to, if any.
+        if mode == "widget" || mode == "floating" {
+            if let Some(corner) = settings.snap_anchor.as_deref() {
+                let _ = snap::snap_to_edge(window, corner);
+            }
+        }
     }
+
     Ok(())
 }
 
+#[tauri::command]
+fn save_window_state(window: WebviewWindow, mode: &str) -> Result<(), String> {
+    window_state::capture_window_state(&window, mode)
+}
+
+#[tauri::command]
+fn restore_window_state(window: WebviewWindow, mode: &str) -> Result<bool, String> {
+    window_state::restore_window_state(&window, mode)
+}
+
 #[tauri::command]
 fn load_settings() -> Settings {
-    load_settings_from_file()
+    load_settings_with_api_key()
 }
 
 #[tauri::command]
 fn save_settings(settings: Settings) -> Result<(), String> {
+    // Only touch the secure store if the key actually changed, and don't let
+    // a keychain/Secret Service failure (no daemon running, locked, etc.)
+    // block saving the rest of the settings.
+    let previous_key = secret_store::get_api_key().unwrap_or_default();
+    if settings.api_key != previous_key {
+        let _ = if settings.api_key.is_empty() {
+            secret_store::clear_api_key()
+        } else {
+            secret_store::set_api_key(&settings.api_key)
+        };
+    }
+
     save_settings_to_file(&settings)
 }
 
+#[tauri::command]
+fn set_api_key(key: String) -> Result<(), String> {
+    secret_store::set_api_key(&key)
+}
+
+#[tauri::command]
+fn get_api_key() -> Option<String> {
+    secret_store::get_api_key()
+}
+
+#[tauri::command]
+fn clear_api_key() -> Result<(), String> {
+    secret_store::clear_api_key()
+}
+
 #[tauri::command]
 async fn hide_window(window: WebviewWindow) -> Result<(), String> {
     window.hide().map_err(|e| e.to_string())
@@ -142,6 +365,72 @@ async fn show_window(window: WebviewWindow) -> Result<(), String> {
     window.set_focus().map_err(|e| e.to_string())
 }
 
+#[tauri::command]
+async fn set_toggle_shortcut(app: tauri::AppHandle, accelerator: String) -> Result<(), String> {
+    let new_shortcut = parse_accelerator(&accelerator)?;
+
+    let mut settings = load_settings_from_file();
+    if accelerator == settings.toggle_shortcut {
+        // Nothing to do: re-registering the same accelerator it's already
+        // bound to would be rejected as a duplicate.
+        return Ok(());
+    }
+    let old_shortcut = parse_accelerator(&settings.toggle_shortcut).ok();
+
+    let shortcuts = app.global_shortcut();
+    let app_handle = app.clone();
+
+    // Register the new shortcut first and only tear down the old one once
+    // that succeeds, so a malformed or already-taken accelerator leaves the
+    // user with a working toggle instead of none at all.
+    shortcuts
+        .on_shortcut(new_shortcut, move |_app, _shortcut, _event| {
+            if let Some(window) = app_handle.get_webview_window("main") {
+                let is_visible = window.is_visible().unwrap_or(false);
+                let is_focused = window.is_focused().unwrap_or(false);
+
+                if !is_visible {
+                    let _ = window.show();
+                    let _ = window.set_focus();
+                } else if is_focused {
+                    let _ = window.hide();
+                } else {
+                    let _ = window.set_focus();
+                }
+            }
+        })
+        .map_err(|e| format!("Shortcut already in use or invalid: {}", e))?;
+
+    if let Some(old_shortcut) = old_shortcut {
+        let _ = shortcuts.unregister(old_shortcut);
+    }
+
+    settings.toggle_shortcut = accelerator;
+    save_settings_to_file(&settings)
+}
+
+#[cfg(feature = "overlay")]
+#[tauri::command]
+fn spawn_overlay(app: tauri::AppHandle) -> Result<(), String> {
+    let settings = load_settings_from_file();
+    overlay::spawn_overlay(&app, settings)
+}
+
+#[tauri::command]
+async fn snap_to_edge(window: WebviewWindow, anchor: &str) -> Result<(), String> {
+    snap::snap_to_edge(&window, anchor)?;
+
+    let mut settings = load_settings_from_file();
+    settings.snap_anchor = Some(anchor.to_string());
+    save_settings_to_file(&settings)
+}
+
+#[tauri::command]
+async fn set_click_through(window: WebviewWindow, enabled: bool) -> Result<(), String> {
+    window.set_ignore_cursor_events(enabled).map_err(|e| e.to_string())?;
+    window.emit("click-through-changed", enabled).map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 async fn send_notification(
     app: tauri::AppHandle,
@@ -166,24 +455,57 @@ pub fn run() {
         .plugin(tauri_plugin_global_shortcut::Builder::new().build())
         .plugin(tauri_plugin_fs::init())
         .manage(Mutex::new(HideState { hide_until: None }))
+        .manage(CurrentMode(Mutex::new(Settings::default().default_mode)))
+        .manage(DragState(Mutex::new(None)))
         .invoke_handler(tauri::generate_handler![
             greet,
             set_window_mode,
             load_settings,
             save_settings,
+            set_api_key,
+            get_api_key,
+            clear_api_key,
             hide_window,
             show_window,
+            set_click_through,
+            set_toggle_shortcut,
+            snap_to_edge,
             send_notification,
+            #[cfg(feature = "overlay")]
+            spawn_overlay,
+            save_window_state,
+            restore_window_state,
         ])
         .setup(|app| {
+            let settings = load_settings_from_file();
+            *app.state::<CurrentMode>().0.lock().unwrap() = settings.default_mode.clone();
+
+            // Restore the saved geometry of the configured default mode before
+            // the window is shown. Mode switches do not update `default_mode`,
+            // so the app starts in that mode, not in the mode last used.
+            if let Some(window) = app.get_webview_window("main") {
+                let _ = apply_window_mode(&window, &settings.default_mode);
+            }
+
             // Build tray menu
             let show = MenuItem::with_id(app, "show", "Show Pal", true, None::<&str>)?;
             let full = MenuItem::with_id(app, "full", "Full window", true, None::<&str>)?;
             let widget = MenuItem::with_id(app, "widget", "Widget mode", true, None::<&str>)?;
             let floating = MenuItem::with_id(app, "floating", "Floating mode", true, None::<&str>)?;
+            let click_through = CheckMenuItem::with_id(
+                app,
+                "click_through",
+                "Click-through",
+                true,
+                false,
+                None::<&str>,
+            )?;
             let quit = MenuItem::with_id(app, "quit", "Quit", true, None::<&str>)?;
 
-            let menu = Menu::with_items(app, &[&show, &full, &widget, &floating, &quit])?;
+            let menu = Menu::with_items(
+                app,
+                &[&show, &full, &widget, &floating, &click_through, &quit],
+            )?;
 
             // Build tray icon
             let _tray = TrayIconBuilder::new()
@@ -218,28 +540,48 @@ pub fn run() {
                         }
                         "full" => {
                             if let Some(window) = app.get_webview_window("main") {
+                                let old_mode = app.state::<CurrentMode>().0.lock().unwrap().clone();
+                                let _ = window_state::capture_window_state(&window, &old_mode);
                                 let _ = window.show();
                                 let _ = window.set_focus();
                                 let _ = apply_window_mode(&window, "full");
+                                *app.state::<CurrentMode>().0.lock().unwrap() = "full".to_string();
                                 let _ = window.emit("mode-changed", "full");
                             }
                         }
                         "widget" => {
                             if let Some(window) = app.get_webview_window("main") {
+                                let old_mode = app.state::<CurrentMode>().0.lock().unwrap().clone();
+                                let _ = window_state::capture_window_state(&window, &old_mode);
                                 let _ = window.show();
                                 let _ = window.set_focus();
                                 let _ = apply_window_mode(&window, "widget");
+                                *app.state::<CurrentMode>().0.lock().unwrap() = "widget".to_string();
                                 let _ = window.emit("mode-changed", "widget");
                             }
                         }
                         "floating" => {
                             if let Some(window) = app.get_webview_window("main") {
+                                let old_mode = app.state::<CurrentMode>().0.lock().unwrap().clone();
+                                let _ = window_state::capture_window_state(&window, &old_mode);
                                 let _ = window.show();
                                 let _ = window.set_focus();
                                 let _ = apply_window_mode(&window, "floating");
+                                *app.state::<CurrentMode>().0.lock().unwrap() = "floating".to_string();
                                 let _ = window.emit("mode-changed", "floating");
                             }
                         }
+                        "click_through" => {
+                            if let Some(window) = app.get_webview_window("main") {
+                                if let Some(item) = app.menu().and_then(|m| m.get("click_through")) {
+                                    if let Some(check_item) = item.as_check_menuitem() {
+                                        let enabled = check_item.is_checked().unwrap_or(false);
+                                        let _ = window.set_ignore_cursor_events(enabled);
+                                        let _ = window.emit("click-through-changed", enabled);
+                                    }
+                                }
+                            }
+                        }
                         "quit" => {
                             std::process::exit(0);
                         }
@@ -248,8 +590,9 @@ pub fn run() {
                 })
                 .build(app)?;
 
-            // Register global shortcut (Ctrl+Shift+P / Cmd+Shift+P)
-            let shortcut = Shortcut::new(Some(Modifiers::CONTROL | Modifiers::SHIFT), Code::KeyP);
+            // Register the user's configured toggle shortcut (defaults to Ctrl+Shift+P).
+            let shortcut = parse_accelerator(&settings.toggle_shortcut)
+                .unwrap_or_else(|_| Shortcut::new(Some(Modifiers::CONTROL | Modifiers::SHIFT), Code::KeyP));
 
             let app_handle = app.handle().clone();
             app.global_shortcut().on_shortcut(shortcut, move |_app, _shortcut, _event| {
@@ -275,12 +618,65 @@ pub fn run() {
             Ok(())
         })
         .on_window_event(|window, event| {
-            if let tauri::WindowEvent::CloseRequested { api, .. } = event {
-                // Don't quit, hide to tray
-                let _ = window.hide();
-                api.prevent_close();
+            match event {
+                tauri::WindowEvent::CloseRequested { api, .. } => {
+                    if let Some(webview_window) = window.app_handle().get_webview_window(window.label()) {
+                        let mode = window.state::<CurrentMode>().0.lock().unwrap().clone();
+                        let _ = window_state::capture_window_state(&webview_window, &mode);
+                    }
+                    // Don't quit, hide to tray
+                    let _ = window.hide();
+                    api.prevent_close();
+                }
+                tauri::WindowEvent::Moved(position) => {
+                    let mode = window.state::<CurrentMode>().0.lock().unwrap().clone();
+                    if mode != "widget" && mode != "floating" {
+                        return;
+                    }
+
+                    let moved_at = std::time::Instant::now();
+                    *window.state::<DragState>().0.lock().unwrap() = Some(moved_at);
+
+                    let Some(webview_window) = window.app_handle().get_webview_window(window.label()) else {
+                        return;
+                    };
+                    let position = *position;
+                    let app_handle = window.app_handle().clone();
+
+                    // Debounce: only treat this as the drag's end if nothing
+                    // moves the window again within the window below.
+                    std::thread::spawn(move || {
+                        std::thread::sleep(std::time::Duration::from_millis(250));
+                        let still_latest = app_handle
+                            .state::<DragState>()
+                            .0
+                            .lock()
+                            .unwrap()
+                            .map(|last| last == moved_at)
+                            .unwrap_or(false);
+                        if !still_latest {
+                            return;
+                        }
+
+                        if let Some(corner) = snap::nearest_corner(&webview_window, position) {
+                            let _ = snap::snap_to_edge(&webview_window, &corner);
+                            let mut settings = load_settings_from_file();
+                            settings.snap_anchor = Some(corner);
+                            let _ = save_settings_to_file(&settings);
+                        }
+                    });
+                }
+                _ => {}
             }
         })
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application")
+        .run(move |_app_handle, _run_event| {
+            // The native egui overlay (when spawned) shares this same loop
+            // instead of running its own, so it just needs a tick here.
+            #[cfg(feature = "overlay")]
+            if let tauri::RunEvent::MainEventsCleared = _run_event {
+                overlay::tick(_app_handle);
+            }
+        });
 }
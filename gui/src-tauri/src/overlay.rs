@@ -0,0 +1,210 @@
+// Native egui-rendered overlay, offered as a lighter-weight alternative to the
+// webview for the widget/floating modes.
+//
+// `eframe`/`winit` own the process's main thread and run their own event
+// loop; Tauri already owns one too (driven from `run()` in `lib.rs`), and a
+// process can only have one native windowing event loop. Spawning
+// `eframe::run_native` on a background thread doesn't avoid that conflict --
+// on macOS winit requires its loop to be created on the main thread and
+// panics otherwise, and on every platform two independent loops fighting
+// over the same windowing system is unsupported.
+//
+// Instead, the overlay is a bare `tauri::Window` (no webview), created and
+// driven by the same tao event loop Tauri already runs. `spawn_overlay`
+// builds the window, a real GL context bound to its native surface (via
+// `raw-gl-context`), and an egui painter on top of that context. `tick` is
+// called once per `RunEvent::MainEventsCleared` from `lib.rs` to paint and
+// present a frame, so there is exactly one event loop in the process
+// throughout.
+
+use std::sync::Mutex;
+
+use egui_glow::glow;
+use raw_gl_context::{GlConfig, GlContext};
+use raw_window_handle::HasWindowHandle;
+use tauri::{AppHandle, Emitter, Manager, WindowEvent};
+
+use crate::Settings;
+
+// How long the overlay will go between repaints when nothing is happening
+// (no input events queued). Keeps a near-static widget from busy-repainting
+// at the event loop's full rate.
+const IDLE_REPAINT_INTERVAL: std::time::Duration = std::time::Duration::from_millis(250);
+
+struct OverlayState {
+    window: tauri::Window,
+    gl_context: GlContext,
+    ctx: egui::Context,
+    painter: Mutex<egui_glow::Painter>,
+    raw_input: Mutex<egui::RawInput>,
+    last_pointer_pos: Mutex<egui::Pos2>,
+    last_tick_at: Mutex<std::time::Instant>,
+    pal_name: String,
+    sounds_enabled: bool,
+}
+
+// `GlContext` wraps a raw platform GL handle (HGLRC/NSOpenGLContext/GLXContext)
+// and isn't `Send`/`Sync` on its own, but `OverlayState` is only ever touched
+// from the thread that owns Tauri's event loop -- `spawn_overlay` and `tick`
+// are both called from there, same as every other window/tray handler in
+// this crate.
+unsafe impl Send for OverlayState {}
+unsafe impl Sync for OverlayState {}
+
+// Builds the borderless, transparent, always-on-top overlay window and wires
+// it into the app's existing event loop. Takes the pal name and sounds flag
+// from the `Settings` passed in at spawn time; an overlay that is already
+// spawned does not pick up later settings changes.
+pub fn spawn_overlay(app: &AppHandle, settings: Settings) -> Result<(), String> {
+    if let Some(state) = app.try_state::<OverlayState>() {
+        // Already spawned; just make sure it's visible.
+        let _ = state.window.show();
+        return Ok(());
+    }
+
+    let window = tauri::WindowBuilder::new(app, "pal-overlay")
+        .decorations(false)
+        .transparent(true)
+        .always_on_top(true)
+        .skip_taskbar(true)
+        .inner_size(150.0, 100.0)
+        .build()
+        .map_err(|e| e.to_string())?;
+
+    // Bind a real GL context to the window's native surface so `egui_glow` has
+    // somewhere to draw and somewhere to present to. `raw-gl-context` does the
+    // platform-specific WGL/CGL/GLX setup against the window's raw handle;
+    // `egui_glow` only rasterizes into whatever context is current, it
+    // doesn't create or own one.
+    let window_handle = window.window_handle().map_err(|e| e.to_string())?;
+    let gl_context = unsafe { GlContext::create(&window_handle, GlConfig::default()) }
+        .map_err(|e| e.to_string())?;
+    unsafe { gl_context.make_current() };
+
+    let glow_context = unsafe {
+        glow::Context::from_loader_function(|symbol| gl_context.get_proc_address(symbol))
+    };
+    let painter = egui_glow::Painter::new(std::sync::Arc::new(glow_context), "", None, false)
+        .map_err(|e| e.to_string())?;
+
+    unsafe { gl_context.make_not_current() };
+
+    let app_handle = app.clone();
+    let label = window.label().to_string();
+    window.on_window_event(move |event| {
+        let Some(state) = app_handle.try_state::<OverlayState>() else {
+            return;
+        };
+        let mut raw_input = state.raw_input.lock().unwrap();
+        match event {
+            WindowEvent::CursorMoved { position, .. } => {
+                let pos = egui::pos2(position.x as f32, position.y as f32);
+                *state.last_pointer_pos.lock().unwrap() = pos;
+                raw_input.events.push(egui::Event::PointerMoved(pos));
+            }
+            WindowEvent::MouseInput { state: button_state, button, .. } => {
+                let Some(button) = map_mouse_button(*button) else {
+                    return;
+                };
+                let pos = *state.last_pointer_pos.lock().unwrap();
+                raw_input.events.push(egui::Event::PointerButton {
+                    pos,
+                    button,
+                    pressed: matches!(button_state, tauri::ElementState::Pressed),
+                    modifiers: egui::Modifiers::default(),
+                });
+            }
+            WindowEvent::Destroyed => {
+                app_handle.unmanage::<OverlayState>();
+                let _ = label.as_str();
+            }
+            _ => {}
+        }
+    });
+
+    app.manage(OverlayState {
+        window,
+        gl_context,
+        ctx: egui::Context::default(),
+        painter: Mutex::new(painter),
+        raw_input: Mutex::new(egui::RawInput::default()),
+        last_pointer_pos: Mutex::new(egui::Pos2::ZERO),
+        last_tick_at: Mutex::new(std::time::Instant::now()),
+        pal_name: settings.pal_name,
+        sounds_enabled: settings.sounds_enabled,
+    });
+
+    Ok(())
+}
+
+fn map_mouse_button(button: tauri::MouseButton) -> Option<egui::PointerButton> {
+    match button {
+        tauri::MouseButton::Left => Some(egui::PointerButton::Primary),
+        tauri::MouseButton::Right => Some(egui::PointerButton::Secondary),
+        tauri::MouseButton::Middle => Some(egui::PointerButton::Middle),
+        _ => None,
+    }
+}
+
+// Paints one egui frame, if the overlay has been spawned. Called from the
+// main `RunEvent::MainEventsCleared` tick in `lib.rs`, so it shares Tauri's
+// single event loop rather than running its own.
+pub fn tick(app: &AppHandle) {
+    let Some(state) = app.try_state::<OverlayState>() else {
+        return;
+    };
+
+    // Skip the repaint entirely if nothing happened and we're still well
+    // within the idle interval -- there's nothing new to draw.
+    let has_input = !state.raw_input.lock().unwrap().events.is_empty();
+    let mut last_tick_at = state.last_tick_at.lock().unwrap();
+    if !has_input && last_tick_at.elapsed() < IDLE_REPAINT_INTERVAL {
+        return;
+    }
+    *last_tick_at = std::time::Instant::now();
+    drop(last_tick_at);
+
+    let raw_input = std::mem::take(&mut *state.raw_input.lock().unwrap());
+    let full_output = state.ctx.run(raw_input, |ctx| {
+        egui::CentralPanel::default()
+            .frame(egui::Frame::none())
+            .show(ctx, |ui| {
+                let sprite = ui.add(
+                    egui::Label::new(egui::RichText::new("\u{1F43E}").size(48.0)).sense(egui::Sense::click()),
+                );
+
+                if sprite.clicked() {
+                    let _ = app.emit("overlay-clicked", &state.pal_name);
+                }
+
+                ui.label(format!("{} is here", state.pal_name));
+                if state.sounds_enabled {
+                    ui.label("\u{1F50A}");
+                }
+            });
+    });
+
+    let clipped_primitives = state.ctx.tessellate(full_output.shapes, full_output.pixels_per_point);
+
+    unsafe { state.gl_context.make_current() };
+
+    let mut painter = state.painter.lock().unwrap();
+    let size = state.window.inner_size().unwrap_or(tauri::PhysicalSize::new(150, 100));
+    painter.paint_and_update_textures(
+        [size.width, size.height],
+        full_output.pixels_per_point,
+        &clipped_primitives,
+        &full_output.textures_delta,
+    );
+    drop(painter);
+
+    // `paint_and_update_textures` only rasterizes into the current context's
+    // back buffer; without swapping, nothing the frame just drew ever makes
+    // it to screen.
+    state.gl_context.swap_buffers();
+    unsafe { state.gl_context.make_not_current() };
+
+    if !full_output.platform_output.copied_text.is_empty() {
+        // Clipboard integration isn't wired up for the overlay; nothing to do.
+    }
+}
@@ -0,0 +1,27 @@
+// Thin wrapper around the platform secure store (Keychain on macOS,
+// Credential Manager on Windows, Secret Service on Linux) so the API key
+// never has to touch settings.json in cleartext.
+
+use keyring::Entry;
+
+const SERVICE: &str = "Pal";
+const USERNAME: &str = "api_key";
+
+fn entry() -> Result<Entry, String> {
+    Entry::new(SERVICE, USERNAME).map_err(|e| e.to_string())
+}
+
+pub fn get_api_key() -> Option<String> {
+    entry().ok()?.get_password().ok()
+}
+
+pub fn set_api_key(key: &str) -> Result<(), String> {
+    entry()?.set_password(key).map_err(|e| e.to_string())
+}
+
+pub fn clear_api_key() -> Result<(), String> {
+    match entry()?.delete_credential() {
+        Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+        Err(e) => Err(e.to_string()),
+    }
+}
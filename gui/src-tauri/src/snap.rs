@@ -0,0 +1,73 @@
+use tauri::{LogicalPosition, PhysicalPosition, Position, WebviewWindow};
+
+// How close (in logical pixels) the window has to be to a monitor edge
+// before a drag release snaps it flush to that edge.
+const SNAP_THRESHOLD: f64 = 24.0;
+
+// Moves `window` flush against the requested edge or corner of its current
+// monitor, accounting for the monitor's DPI scale factor. The monitor's full
+// bounds are used; space taken by taskbars or docks is not excluded. Corners
+// pin both axes; a plain edge (e.g. "left") pins one axis and leaves the
+// other where the window already is.
+pub fn snap_to_edge(window: &WebviewWindow, anchor: &str) -> Result<(), String> {
+    let monitor = window
+        .current_monitor()
+        .map_err(|e| e.to_string())?
+        .ok_or("No monitor found for window")?;
+
+    let scale_factor = monitor.scale_factor();
+    let monitor_pos = monitor.position().to_logical::<f64>(scale_factor);
+    let monitor_size = monitor.size().to_logical::<f64>(scale_factor);
+    let window_size = window.outer_size().map_err(|e| e.to_string())?.to_logical::<f64>(scale_factor);
+    let current_pos = window.outer_position().map_err(|e| e.to_string())?.to_logical::<f64>(scale_factor);
+
+    let left = monitor_pos.x;
+    let right = monitor_pos.x + monitor_size.width - window_size.width;
+    let top = monitor_pos.y;
+    let bottom = monitor_pos.y + monitor_size.height - window_size.height;
+
+    let (x, y) = match anchor {
+        "top-left" => (left, top),
+        "top-right" => (right, top),
+        "bottom-left" => (left, bottom),
+        "bottom-right" => (right, bottom),
+        "left" => (left, current_pos.y),
+        "right" => (right, current_pos.y),
+        "top" => (current_pos.x, top),
+        "bottom" => (current_pos.x, bottom),
+        _ => return Err(format!("Unknown anchor: {}", anchor)),
+    };
+
+    window
+        .set_position(Position::Logical(LogicalPosition::new(x, y)))
+        .map_err(|e| e.to_string())
+}
+
+// Checks whether `position` sits within `SNAP_THRESHOLD` of a monitor edge
+// and, if so, returns the corner or edge it should snap to.
+pub fn nearest_corner(window: &WebviewWindow, position: PhysicalPosition<i32>) -> Option<String> {
+    let monitor = window.current_monitor().ok()??;
+    let scale_factor = monitor.scale_factor();
+    let monitor_pos = monitor.position().to_logical::<f64>(scale_factor);
+    let monitor_size = monitor.size().to_logical::<f64>(scale_factor);
+    let window_size = window.outer_size().ok()?.to_logical::<f64>(scale_factor);
+    let position = position.to_logical::<f64>(scale_factor);
+
+    let near_left = (position.x - monitor_pos.x).abs() < SNAP_THRESHOLD;
+    let near_right = ((monitor_pos.x + monitor_size.width) - (position.x + window_size.width)).abs() < SNAP_THRESHOLD;
+    let near_top = (position.y - monitor_pos.y).abs() < SNAP_THRESHOLD;
+    let near_bottom =
+        ((monitor_pos.y + monitor_size.height) - (position.y + window_size.height)).abs() < SNAP_THRESHOLD;
+
+    match (near_left, near_right, near_top, near_bottom) {
+        (true, _, true, _) => Some("top-left".to_string()),
+        (_, true, true, _) => Some("top-right".to_string()),
+        (true, _, _, true) => Some("bottom-left".to_string()),
+        (_, true, _, true) => Some("bottom-right".to_string()),
+        (true, _, _, _) => Some("left".to_string()),
+        (_, true, _, _) => Some("right".to_string()),
+        (_, _, true, _) => Some("top".to_string()),
+        (_, _, _, true) => Some("bottom".to_string()),
+        _ => None,
+    }
+}
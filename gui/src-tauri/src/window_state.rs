@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+use tauri::{LogicalPosition, LogicalSize, Position, Size, WebviewWindow};
+
+use crate::get_config_dir;
+
+// Per-mode window geometry, persisted so each mode (full/widget/floating) remembers
+// where the user last left it. Kept separate from settings.json since it changes
+// on every move/resize rather than on explicit user action.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WindowState {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+    pub maximized: bool,
+}
+
+type WindowStates = HashMap<String, WindowState>;
+
+fn get_window_state_path() -> PathBuf {
+    get_config_dir().join("window-state.json")
+}
+
+pub fn load_window_states() -> WindowStates {
+    let path = get_window_state_path();
+    if path.exists() {
+        if let Ok(content) = fs::read_to_string(&path) {
+            if let Ok(states) = serde_json::from_str(&content) {
+                return states;
+            }
+        }
+    }
+    HashMap::new()
+}
+
+fn save_window_states(states: &WindowStates) -> Result<(), String> {
+    let dir = get_config_dir();
+    fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+
+    let path = get_window_state_path();
+    let content = serde_json::to_string_pretty(states).map_err(|e| e.to_string())?;
+    fs::write(&path, content).map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+// Captures the window's current geometry and stores it under `mode`.
+pub fn capture_window_state(window: &WebviewWindow, mode: &str) -> Result<(), String> {
+    let mut states = load_window_states();
+
+    let scale_factor = window.scale_factor().map_err(|e| e.to_string())?;
+    let position = window.outer_position().map_err(|e| e.to_string())?;
+    let size = window.inner_size().map_err(|e| e.to_string())?;
+    let position = position.to_logical::<f64>(scale_factor);
+    let size = size.to_logical::<f64>(scale_factor);
+
+    states.insert(
+        mode.to_string(),
+        WindowState {
+            x: position.x,
+            y: position.y,
+            width: size.width,
+            height: size.height,
+            maximized: window.is_maximized().unwrap_or(false),
+        },
+    );
+
+    save_window_states(&states)
+}
+
+// Restores the geometry saved for `mode`, if any. Returns `true` when a saved
+// state existed and was applied, so callers can fall back to hardcoded defaults.
+pub fn restore_window_state(window: &WebviewWindow, mode: &str) -> Result<bool, String> {
+    let states = load_window_states();
+    let Some(state) = states.get(mode) else {
+        return Ok(false);
+    };
+
+    let (x, y) = clamp_to_available_monitors(window, state.x, state.y, state.width, state.height)?;
+
+    window
+        .set_size(Size::Logical(LogicalSize::new(state.width, state.height)))
+        .map_err(|e| e.to_string())?;
+    window
+        .set_position(Position::Logical(LogicalPosition::new(x, y)))
+        .map_err(|e| e.to_string())?;
+    if state.maximized {
+        window.maximize().map_err(|e| e.to_string())?;
+    }
+
+    Ok(true)
+}
+
+// If the saved position no longer lands on any currently available monitor
+// (a monitor was unplugged, or the resolution changed since the last
+// session), fall back to the primary monitor's origin instead of leaving a
+// borderless, undecorated window stranded off-screen with no way to drag it
+// back. Mirrors the approach `tauri-plugin-window-state` takes.
+fn clamp_to_available_monitors(
+    window: &WebviewWindow,
+    x: f64,
+    y: f64,
+    width: f64,
+    height: f64,
+) -> Result<(f64, f64), String> {
+    let monitors = window.available_monitors().map_err(|e| e.to_string())?;
+
+    let center_x = x + width / 2.0;
+    let center_y = y + height / 2.0;
+    let fits_a_monitor = monitors.iter().any(|monitor| {
+        let scale_factor = monitor.scale_factor();
+        let position = monitor.position().to_logical::<f64>(scale_factor);
+        let size = monitor.size().to_logical::<f64>(scale_factor);
+        center_x >= position.x
+            && center_x <= position.x + size.width
+            && center_y >= position.y
+            && center_y <= position.y + size.height
+    });
+
+    if fits_a_monitor {
+        return Ok((x, y));
+    }
+
+    match window.primary_monitor().map_err(|e| e.to_string())? {
+        Some(monitor) => {
+            let scale_factor = monitor.scale_factor();
+            let position = monitor.position().to_logical::<f64>(scale_factor);
+            Ok((position.x, position.y))
+        }
+        None => Ok((0.0, 0.0)),
+    }
+}